@@ -9,6 +9,8 @@ export const WH_BASE_CHAIN_ID = 30
 
 export const WH_SOLANA_CHAIN_ID = 1
 
+export const MINTING_LIMIT = "18446744073709551615" // Max u64
+
 // EVM addresses converted to 32 bytes. 0x is trimmed intentionally as the input
 // param requires it without leading 0x.
 
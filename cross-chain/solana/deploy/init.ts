@@ -66,7 +66,6 @@ async function run(): Promise<void> {
     wormholeGatewayProgram.programId
   )[0]
 
-  const mintingLimit = "18446744073709551615" // Max u64
   let WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_TESTNET
   if (process.env.CLUSTER === "mainnet") {
     WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_MAINNET
@@ -90,7 +89,7 @@ async function run(): Promise<void> {
 
   // Initialize wormhole gateway
   await wormholeGatewayProgram.methods
-    .initialize(new anchor.BN(mintingLimit))
+    .initialize(new anchor.BN(consts.MINTING_LIMIT))
     .accounts({
       authority,
       custodian: minter,
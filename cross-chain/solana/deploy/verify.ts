@@ -0,0 +1,214 @@
+import * as anchor from "@coral-xyz/anchor"
+import fs from "fs"
+import { PublicKey, Keypair } from "@solana/web3.js"
+import { getMint } from "@solana/spl-token"
+import dotenv from "dotenv"
+import { Program } from "@coral-xyz/anchor"
+import { Tbtc } from "../target/types/tbtc"
+import { WormholeGateway } from "../target/types/wormhole_gateway"
+import {
+  Metadata,
+  PROGRAM_ID as METADATA_PROGRAM_ID,
+} from "@metaplex-foundation/mpl-token-metadata"
+import * as consts from "./helpers/consts"
+
+type Check = {
+  name: string
+  expected: string
+  actual: string
+}
+
+// Verifies the accounts created by `init.ts` and prints an attestation report.
+// Exits with a non-zero code if any check fails, so it can gate the next step
+// of the deployment ceremony.
+async function run(): Promise<void> {
+  dotenv.config({ path: "../solana.env" })
+
+  anchor.setProvider(anchor.AnchorProvider.env())
+
+  // Right after `init.ts`, both programs are owned by the deployer key. Once
+  // the authority has been handed over, pass the new owner (e.g. the council
+  // multisig) as EXPECTED_AUTHORITY.
+  const expectedAuthority = process.env.EXPECTED_AUTHORITY
+    ? new PublicKey(process.env.EXPECTED_AUTHORITY)
+    : loadKey(process.env.AUTHORITY).publicKey
+
+  const tbtcProgram = anchor.workspace.Tbtc as Program<Tbtc>
+  const wormholeGatewayProgram = anchor.workspace
+    .WormholeGateway as Program<WormholeGateway>
+  const connection = tbtcProgram.provider.connection
+
+  const mint = PublicKey.findProgramAddressSync(
+    [Buffer.from("tbtc-mint")],
+    tbtcProgram.programId
+  )[0]
+
+  const config = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    tbtcProgram.programId
+  )[0]
+
+  const tbtcMetadata = PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    METADATA_PROGRAM_ID
+  )[0]
+
+  const custodian = PublicKey.findProgramAddressSync(
+    [Buffer.from("redeemer")],
+    wormholeGatewayProgram.programId
+  )[0]
+
+  const minterInfo = PublicKey.findProgramAddressSync(
+    [Buffer.from("minter-info"), custodian.toBuffer()],
+    tbtcProgram.programId
+  )[0]
+
+  const gatewayInfo = (chain: number) => {
+    const encodedChain = Buffer.alloc(2)
+    encodedChain.writeUInt16LE(chain)
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("gateway-info"), encodedChain],
+      wormholeGatewayProgram.programId
+    )[0]
+  }
+
+  let WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_TESTNET
+  let ARBITRUM_GATEWAY = consts.ARBITRUM_GATEWAY_ADDRESS_TESTNET
+  let OPTIMISM_GATEWAY = consts.OPTIMISM_GATEWAY_ADDRESS_TESTNET
+  let POLYGON_GATEWAY = consts.POLYGON_GATEWAY_ADDRESS_TESTNET
+  let BASE_GATEWAY = consts.BASE_GATEWAY_ADDRESS_TESTNET
+  let SOLANA_GATEWAY = consts.SOLANA_GATEWAY_ADDRESS_TESTNET
+  if (process.env.CLUSTER === "mainnet") {
+    WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_MAINNET
+    ARBITRUM_GATEWAY = consts.ARBITRUM_GATEWAY_ADDRESS_MAINNET
+    OPTIMISM_GATEWAY = consts.OPTIMISM_GATEWAY_ADDRESS_MAINNET
+    POLYGON_GATEWAY = consts.POLYGON_GATEWAY_ADDRESS_MAINNET
+    BASE_GATEWAY = consts.BASE_GATEWAY_ADDRESS_MAINNET
+    SOLANA_GATEWAY = consts.SOLANA_GATEWAY_ADDRESS_MAINNET
+  }
+
+  const checks: Check[] = []
+  const check = (name: string, expected: unknown, actual: unknown) =>
+    checks.push({ name, expected: String(expected), actual: String(actual) })
+
+  // The mint must only be mintable by the tbtc program and must not be
+  // freezable by anyone.
+  const mintState = await getMint(connection, mint)
+  check("tBTC mint decimals", 8, mintState.decimals)
+  check("tBTC mint authority", config, mintState.mintAuthority)
+  check("tBTC freeze authority", null, mintState.freezeAuthority)
+
+  const metadataState = await Metadata.fromAccountAddress(
+    connection,
+    tbtcMetadata
+  )
+  check("tBTC metadata mint", mint, metadataState.mint)
+  check(
+    "tBTC metadata update authority",
+    config,
+    metadataState.updateAuthority
+  )
+
+  const configState = await tbtcProgram.account.config.fetch(config)
+  check("tbtc config mint", mint, configState.mint)
+  check("tbtc paused", false, configState.paused)
+  check("tbtc authority", expectedAuthority, configState.authority)
+  check("tbtc pending authority", null, configState.pendingAuthority ?? null)
+
+  const custodianState = await wormholeGatewayProgram.account.custodian.fetch(
+    custodian
+  )
+  check("gateway tBTC mint", mint, custodianState.tbtcMint)
+  check(
+    "gateway wrapped tBTC mint",
+    WRAPPED_TBTC,
+    custodianState.wrappedTbtcMint
+  )
+  check("gateway authority", expectedAuthority, custodianState.authority)
+  check(
+    "gateway pending authority",
+    null,
+    custodianState.pendingAuthority ?? null
+  )
+  check(
+    "gateway minting limit",
+    consts.MINTING_LIMIT,
+    custodianState.mintingLimit
+  )
+
+  // The gateway addresses are hand-typed hex, so compare each registered
+  // address against the constant `init.ts` used for this cluster.
+  const gateways: [string, number, string][] = [
+    ["Arbitrum", consts.WH_ARBITRUM_CHAIN_ID, ARBITRUM_GATEWAY],
+    ["Optimism", consts.WH_OPTIMISM_CHAIN_ID, OPTIMISM_GATEWAY],
+    ["Polygon", consts.WH_POLYGON_CHAIN_ID, POLYGON_GATEWAY],
+    ["Base", consts.WH_BASE_CHAIN_ID, BASE_GATEWAY],
+    [
+      "Solana",
+      consts.WH_SOLANA_CHAIN_ID,
+      new PublicKey(SOLANA_GATEWAY).toBuffer().toString("hex"),
+    ],
+  ]
+  for (const [name, chain, expected] of gateways) {
+    const gatewayInfoState =
+      await wormholeGatewayProgram.account.gatewayInfo.fetchNullable(
+        gatewayInfo(chain)
+      )
+    check(
+      `${name} gateway address`,
+      expected.toLowerCase(),
+      gatewayInfoState
+        ? Buffer.from(gatewayInfoState.address).toString("hex")
+        : null
+    )
+  }
+
+  const minterInfoState = await tbtcProgram.account.minterInfo.fetchNullable(
+    minterInfo
+  )
+  check("gateway is tbtc minter", custodian, minterInfoState?.minter ?? null)
+
+  console.log("tbtc program:", tbtcProgram.programId.toBase58())
+  console.log(
+    "wormhole gateway program:",
+    wormholeGatewayProgram.programId.toBase58()
+  )
+  console.log()
+
+  let failed = 0
+  for (const { name, expected, actual } of checks) {
+    if (expected === actual) {
+      console.log(`PASS ${name}: ${actual}`)
+    } else {
+      failed++
+      console.log(`FAIL ${name}: expected ${expected}, got ${actual}`)
+    }
+  }
+
+  console.log()
+  console.log(`${checks.length - failed}/${checks.length} checks passed`)
+
+  if (failed > 0) {
+    process.exitCode = 1
+  }
+}
+
+;(async () => {
+  try {
+    await run()
+  } catch (e) {
+    console.log("Exception called:", e)
+    process.exitCode = 1
+  }
+})()
+
+function loadKey(filename: string): Keypair {
+  try {
+    const contents = fs.readFileSync(filename).toString()
+    const bs = Uint8Array.from(JSON.parse(contents))
+
+    return Keypair.fromSecretKey(bs)
+  } catch {
+    console.log("Unable to read keypair...", filename)
+  }
+}
@@ -17,6 +17,12 @@ pub enum WormholeGatewayError {
     #[msg("0x0 recipient not allowed")]
     ZeroRecipient = 0x30,
 
+    #[msg("Recipient padding does not match the chain's address format")]
+    InvalidRecipientPadding = 0x32,
+
+    #[msg("Address format length must be between 1 and 32 bytes")]
+    InvalidAddressFormat = 0x36,
+
     #[msg("Not enough wormhole tBTC in the gateway to bridge")]
     NotEnoughWrappedTbtc = 0x40,
 
@@ -1,3 +1,4 @@
+use crate::state::AddressFormat;
 use anchor_lang::prelude::*;
 
 #[event]
@@ -28,6 +29,17 @@ pub struct GatewayAddressUpdated {
     pub gateway: [u8; 32],
 }
 
+#[event]
+pub struct AddressFormatUpdated {
+    pub chain: u16,
+    pub address_format: AddressFormat,
+}
+
+#[event]
+pub struct AddressFormatRemoved {
+    pub chain: u16,
+}
+
 #[event]
 pub struct MintingLimitUpdated {
     pub minting_limit: u64,
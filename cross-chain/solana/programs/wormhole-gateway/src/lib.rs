@@ -53,6 +53,17 @@ pub mod wormhole_gateway {
         processor::update_gateway_address(ctx, args)
     }
 
+    pub fn update_address_format(
+        ctx: Context<UpdateAddressFormat>,
+        args: UpdateAddressFormatArgs,
+    ) -> Result<()> {
+        processor::update_address_format(ctx, args)
+    }
+
+    pub fn remove_address_format(ctx: Context<RemoveAddressFormat>, chain: u16) -> Result<()> {
+        processor::remove_address_format(ctx, chain)
+    }
+
     pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
         processor::update_minting_limit(ctx, new_limit)
     }
@@ -75,6 +86,13 @@ pub mod wormhole_gateway {
         processor::send_tbtc_wrapped(ctx, args)
     }
 
+    pub fn validate_recipient(
+        ctx: Context<ValidateRecipient>,
+        args: ValidateRecipientArgs,
+    ) -> Result<()> {
+        processor::validate_recipient(ctx, args)
+    }
+
     pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) -> Result<()> {
         processor::deposit_wormhole_tbtc(ctx, amount)
     }
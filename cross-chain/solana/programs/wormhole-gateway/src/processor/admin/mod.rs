@@ -7,9 +7,15 @@ pub use change_authority::*;
 mod initialize;
 pub use initialize::*;
 
+mod remove_address_format;
+pub use remove_address_format::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod update_address_format;
+pub use update_address_format::*;
+
 mod update_gateway_address;
 pub use update_gateway_address::*;
 
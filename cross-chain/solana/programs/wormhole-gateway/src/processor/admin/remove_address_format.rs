@@ -0,0 +1,34 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AddressFormatInfo, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RemoveAddressFormat<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// Closing this account means sends to this chain are no longer checked against a format.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [AddressFormatInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump = address_format_info.bump,
+    )]
+    address_format_info: Account<'info, AddressFormatInfo>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+}
+
+pub fn remove_address_format(_ctx: Context<RemoveAddressFormat>, chain: u16) -> Result<()> {
+    emit!(crate::event::AddressFormatRemoved { chain });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AddressFormat, AddressFormatInfo, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: UpdateAddressFormatArgs)]
+pub struct UpdateAddressFormat<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AddressFormatInfo::INIT_SPACE,
+        seeds = [AddressFormatInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump,
+    )]
+    address_format_info: Account<'info, AddressFormatInfo>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateAddressFormat<'info> {
+    fn constraints(args: &UpdateAddressFormatArgs) -> Result<()> {
+        args.address_format.validate()
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAddressFormatArgs {
+    chain: u16,
+    address_format: AddressFormat,
+}
+
+#[access_control(UpdateAddressFormat::constraints(&args))]
+pub fn update_address_format(
+    ctx: Context<UpdateAddressFormat>,
+    args: UpdateAddressFormatArgs,
+) -> Result<()> {
+    let UpdateAddressFormatArgs {
+        chain,
+        address_format,
+    } = args;
+
+    ctx.accounts
+        .address_format_info
+        .set_inner(AddressFormatInfo {
+            bump: ctx.bumps["address_format_info"],
+            address_format,
+        });
+
+    emit!(crate::event::AddressFormatUpdated {
+        chain,
+        address_format
+    });
+
+    Ok(())
+}
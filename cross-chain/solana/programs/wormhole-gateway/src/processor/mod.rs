@@ -9,3 +9,6 @@ pub use receive_tbtc::*;
 
 mod send_tbtc;
 pub use send_tbtc::*;
+
+mod validate_recipient;
+pub use validate_recipient::*;
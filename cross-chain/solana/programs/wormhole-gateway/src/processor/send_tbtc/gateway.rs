@@ -1,6 +1,6 @@
 use crate::{
     constants::MSG_SEED_PREFIX,
-    state::{Custodian, GatewayInfo},
+    state::{AddressFormatInfo, Custodian, GatewayInfo},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -99,6 +99,16 @@ pub struct SendTbtcGateway<'info> {
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
     system_program: Program<'info, System>,
+
+    /// CHECK: This account only exists if an address format was set for the recipient chain. Its
+    /// address is pinned by the seeds, so the sender cannot skip the format check by passing
+    /// another account. It is the last account so that the accounts before it keep the positions
+    /// they had before address formats existed.
+    #[account(
+        seeds = [AddressFormatInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    address_format_info: UncheckedAccount<'info>,
 }
 
 impl<'info> SendTbtcGateway<'info> {
@@ -106,6 +116,7 @@ impl<'info> SendTbtcGateway<'info> {
         super::validate_send(
             &ctx.accounts.wrapped_tbtc_token,
             &args.recipient,
+            AddressFormatInfo::load(&ctx.accounts.address_format_info)?,
             args.amount,
         )
     }
@@ -5,16 +5,28 @@ mod wrapped;
 pub use wrapped::*;
 
 use crate::error::WormholeGatewayError;
-use crate::state::Custodian;
+use crate::state::{AddressFormat, Custodian};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
+/// Checks the recipient rules shared by both send instructions and `validate_recipient`.
+pub fn check_recipient(recipient: &[u8; 32], address_format: Option<AddressFormat>) -> Result<()> {
+    require!(*recipient != [0; 32], WormholeGatewayError::ZeroRecipient);
+
+    // Recipient must be encoded the way the target chain expects, if this is configured.
+    match address_format {
+        Some(address_format) => address_format.validate_recipient(recipient),
+        None => Ok(()),
+    }
+}
+
 pub fn validate_send(
     wrapped_tbtc_token: &Account<'_, token::TokenAccount>,
     recipient: &[u8; 32],
+    address_format: Option<AddressFormat>,
     amount: u64,
 ) -> Result<()> {
-    require!(*recipient != [0; 32], WormholeGatewayError::ZeroRecipient);
+    check_recipient(recipient, address_format)?;
     require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
 
     // Check that the wrapped tBTC in custody is at least enough to bridge out.
@@ -1,4 +1,7 @@
-use crate::{constants::MSG_SEED_PREFIX, state::Custodian};
+use crate::{
+    constants::MSG_SEED_PREFIX,
+    state::{AddressFormatInfo, Custodian},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use wormhole_anchor_sdk::{
@@ -85,6 +88,14 @@ pub struct SendTbtcWrapped<'info> {
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
     system_program: Program<'info, System>,
+
+    /// CHECK: This account only exists if an address format was set for the recipient chain. Like
+    /// in `SendTbtcGateway`, its address is pinned by the seeds and it is the last account.
+    #[account(
+        seeds = [AddressFormatInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    address_format_info: UncheckedAccount<'info>,
 }
 
 impl<'info> SendTbtcWrapped<'info> {
@@ -92,6 +103,7 @@ impl<'info> SendTbtcWrapped<'info> {
         super::validate_send(
             &ctx.accounts.wrapped_tbtc_token,
             &args.recipient,
+            AddressFormatInfo::load(&ctx.accounts.address_format_info)?,
             args.amount,
         )
     }
@@ -0,0 +1,32 @@
+use crate::state::AddressFormatInfo;
+use anchor_lang::prelude::*;
+
+/// Checks a recipient against the same rules `send_tbtc_gateway` and `send_tbtc_wrapped` enforce,
+/// without moving any tokens. This instruction is meant to be simulated so a client can learn which
+/// rule (if any) a recipient violates before asking the sender to sign a transfer.
+#[derive(Accounts)]
+#[instruction(args: ValidateRecipientArgs)]
+pub struct ValidateRecipient<'info> {
+    /// CHECK: This account only exists if an address format was set for this chain.
+    #[account(
+        seeds = [AddressFormatInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump,
+    )]
+    address_format_info: UncheckedAccount<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ValidateRecipientArgs {
+    chain: u16,
+    recipient: [u8; 32],
+}
+
+pub fn validate_recipient(
+    ctx: Context<ValidateRecipient>,
+    args: ValidateRecipientArgs,
+) -> Result<()> {
+    super::check_recipient(
+        &args.recipient,
+        AddressFormatInfo::load(&ctx.accounts.address_format_info)?,
+    )
+}
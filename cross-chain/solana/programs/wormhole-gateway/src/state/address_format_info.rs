@@ -0,0 +1,68 @@
+use crate::error::WormholeGatewayError;
+use anchor_lang::prelude::*;
+
+/// Rules the recipient of a transfer to a chain must satisfy. This account only exists while a
+/// format is set for the chain, so chains without one accept any non-zero recipient.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct AddressFormatInfo {
+    pub bump: u8,
+    pub address_format: AddressFormat,
+}
+
+impl AddressFormatInfo {
+    pub const SEED_PREFIX: &'static [u8] = b"address-format";
+
+    /// Reads the address format for a chain whose address format account may not exist (i.e. the
+    /// authority never set a format for it, or cleared it).
+    pub fn load(info: &AccountInfo) -> Result<Option<AddressFormat>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+
+        let address_format_info = Account::<AddressFormatInfo>::try_from(info)?;
+        Ok(Some(address_format_info.address_format))
+    }
+}
+
+/// Describes how a chain's native address is encoded in the 32-byte Wormhole recipient. Checksums
+/// that only exist in an address's text form (e.g. EIP-55 letter case) are not part of these bytes,
+/// so checking them is left to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct AddressFormat {
+    /// Number of bytes of the native address (e.g. 20 for EVM chains).
+    pub length: u8,
+    pub padding: AddressPadding,
+}
+
+/// Which side of the 32-byte recipient the zero padding must be on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum AddressPadding {
+    Left,
+    Right,
+}
+
+impl AddressFormat {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.length > 0 && self.length <= 32,
+            WormholeGatewayError::InvalidAddressFormat
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_recipient(&self, recipient: &[u8; 32]) -> Result<()> {
+        let length = usize::from(self.length);
+        let padding = match self.padding {
+            AddressPadding::Left => &recipient[..32 - length],
+            AddressPadding::Right => &recipient[length..],
+        };
+        require!(
+            padding.iter().all(|&b| b == 0),
+            WormholeGatewayError::InvalidRecipientPadding
+        );
+
+        Ok(())
+    }
+}
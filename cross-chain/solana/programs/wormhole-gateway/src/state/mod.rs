@@ -1,3 +1,6 @@
+mod address_format_info;
+pub use address_format_info::*;
+
 mod custodian;
 pub use custodian::*;
 
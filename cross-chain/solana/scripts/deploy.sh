@@ -47,5 +47,11 @@ fi
 ## And now it's time to initialize tbtc and wormhole_gatewa programs
 # make init_programs
 
+## Verify mint authority, freeze authority and program accounts, and keep the
+## printed report as the attestation of this deployment. It exits non-zero if
+## any check fails. After the authority handover, run it again with
+## EXPECTED_AUTHORITY set to the new authority.
+# make verify_programs
+
 ## Publishing IDL so that Solana ecosystem can detect and display data nicely (nice to have)
 # anchor idl init --provider.cluster $CLUSTER --provider.wallet $AUTHORITY -f target/idl/<program_idl>.json <program_id>
\ No newline at end of file
@@ -307,6 +307,328 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([failingIx], [imposter], "IsNotAuthority");
     });
+
+    it("set address format", async () => {
+      // EVM addresses are 20 bytes left-padded with zeros.
+      const addressFormat = {
+        length: 20,
+        padding: { left: {} },
+      };
+      const ix = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        { chain, addressFormat }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const addressFormatInfoState =
+        await wormholeGateway.getAddressFormatInfo(chain);
+      expect(addressFormatInfoState.addressFormat).to.eql(addressFormat);
+
+      // The gateway info account layout is untouched.
+      const gatewayInfo = await connection.getAccountInfo(
+        wormholeGateway.getGatewayInfoPDA(chain)
+      );
+      expect(gatewayInfo.data.length).to.equal(41);
+    });
+
+    it("update gateway address keeps address format", async () => {
+      const goodAddress = Array.from(ethereumTokenBridge.address);
+      const ix = await wormholeGateway.updateGatewayAddress(
+        {
+          authority: authority.publicKey,
+        },
+        { chain, address: goodAddress }
+      );
+      await expectIxSuccess([ix], [authority]);
+      await wormholeGateway.checkGateway(chain, goodAddress);
+
+      const addressFormatInfoState =
+        await wormholeGateway.getAddressFormatInfo(chain);
+      expect(addressFormatInfoState.addressFormat.length).to.equal(20);
+    });
+
+    it("remove address format", async () => {
+      const ix = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        chain
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      // Removing the format closes its account.
+      const addressFormatInfo = await connection.getAccountInfo(
+        wormholeGateway.getAddressFormatInfoPDA(chain)
+      );
+      expect(addressFormatInfo).is.null;
+    });
+
+    it("cannot set address format (invalid length)", async () => {
+      const ix = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain,
+          addressFormat: {
+            length: 33,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxFail([ix], [authority], "InvalidAddressFormat");
+    });
+
+    it("cannot set address format (zero length)", async () => {
+      const ix = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain,
+          addressFormat: {
+            length: 0,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxFail([ix], [authority], "InvalidAddressFormat");
+    });
+
+    it("set address format (no gateway)", async () => {
+      // Chains only reached through send_tbtc_wrapped have no gateway.
+      const noGatewayChain = 69;
+      const addressFormat = {
+        length: 20,
+        padding: { left: {} },
+      };
+      const ix = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        { chain: noGatewayChain, addressFormat }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const addressFormatInfoState =
+        await wormholeGateway.getAddressFormatInfo(noGatewayChain);
+      expect(addressFormatInfoState.addressFormat).to.eql(addressFormat);
+
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        noGatewayChain
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
+
+    it("cannot set address format (not authority)", async () => {
+      const ix = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: imposter.publicKey,
+        },
+        {
+          chain,
+          addressFormat: {
+            length: 20,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot remove address format (not set)", async () => {
+      const ix = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        chain
+      );
+      await expectIxFail([ix], [authority], "AccountNotInitialized");
+    });
+
+    it("cannot remove address format (not authority)", async () => {
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain,
+          addressFormat: {
+            length: 20,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      const ix = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: imposter.publicKey,
+        },
+        chain
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        chain
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
+  });
+
+  describe("validate recipient", () => {
+    const chain = 2;
+
+    // EVM addresses are 20 bytes left-padded with zeros.
+    const evmAddressFormat = {
+      length: 20,
+      padding: { left: {} },
+    };
+
+    it("validate recipient (no address format)", async () => {
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxSuccess([ix], [txPayer]);
+    });
+
+    it("cannot validate recipient (recipient is zero address)", async () => {
+      const recipient = Array.from(Buffer.alloc(32));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxFail([ix], [txPayer], "ZeroRecipient");
+    });
+
+    it("validate recipient (no gateway)", async () => {
+      // send_tbtc_wrapped accepts chains without a registered gateway.
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain: 69, recipient }
+      );
+      await expectIxSuccess([ix], [txPayer]);
+    });
+
+    it("cannot validate recipient (no gateway, invalid padding)", async () => {
+      const noGatewayChain = 69;
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        { chain: noGatewayChain, addressFormat: evmAddressFormat }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain: noGatewayChain, recipient }
+      );
+      await expectIxFail([ix], [txPayer], "InvalidRecipientPadding");
+
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        noGatewayChain
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
+
+    it("validate recipient (padded address)", async () => {
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        { chain, addressFormat: evmAddressFormat }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(12), Buffer.alloc(20, "deadbeef", "hex")])
+      );
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxSuccess([ix], [txPayer]);
+    });
+
+    it("cannot validate recipient (invalid padding)", async () => {
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxFail([ix], [txPayer], "InvalidRecipientPadding");
+    });
+
+    it("validate recipient (right-padded address)", async () => {
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain,
+          addressFormat: {
+            length: 20,
+            padding: { right: {} },
+          },
+        }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(20, "deadbeef", "hex"), Buffer.alloc(12)])
+      );
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxSuccess([ix], [txPayer]);
+    });
+
+    it("cannot validate recipient (not right-padded)", async () => {
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(12), Buffer.alloc(20, "deadbeef", "hex")])
+      );
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxFail([ix], [txPayer], "InvalidRecipientPadding");
+    });
+
+    it("cannot validate recipient (zero address with address format)", async () => {
+      const recipient = Array.from(Buffer.alloc(32));
+      const ix = await wormholeGateway.validateRecipientIx(
+        {},
+        { chain, recipient }
+      );
+      await expectIxFail([ix], [txPayer], "ZeroRecipient");
+    });
+
+    after("remove address format", async () => {
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        chain
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
   });
 
   describe("deposit wrapped tbtc", () => {
@@ -1072,6 +1394,223 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([ix], [commonTokenOwner], "AccountNotInitialized");
     });
+
+    it("send tbtc to gateway (left-padded address format)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway and require EVM-style recipients.
+      const recipientChain = 2;
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain: recipientChain,
+          addressFormat: {
+            length: 20,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      // Check token accounts.
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // This recipient is zero padded on the left.
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(12), Buffer.alloc(20, "deadbeef", "hex")])
+      );
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      // Check token accounts after sending tbtc.
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+
+      // Check balance change.
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - sendAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+    });
+
+    it("cannot send tbtc to gateway (recipient not left-padded)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+
+      // This recipient has no zero padding.
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "InvalidRecipientPadding");
+    });
+
+    it("send tbtc to gateway (right-padded address format)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway and require right-padded recipients.
+      const recipientChain = 2;
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain: recipientChain,
+          addressFormat: {
+            length: 20,
+            padding: { right: {} },
+          },
+        }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      // Check token accounts.
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // This recipient is zero padded on the right.
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(20, "deadbeef", "hex"), Buffer.alloc(12)])
+      );
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      // Check token accounts after sending tbtc.
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+
+      // Check balance change.
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - sendAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+    });
+
+    it("cannot send tbtc to gateway (recipient not right-padded)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+
+      // This recipient is left-padded, which the right-padded format rejects.
+      const recipient = Array.from(
+        Buffer.concat([Buffer.alloc(12), Buffer.alloc(20, "deadbeef", "hex")])
+      );
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "InvalidRecipientPadding");
+    });
+
+    after("remove address format", async () => {
+      // Later tests send to this chain without an address format.
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        2
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
   });
 
   describe("send wrapped tbtc", () => {
@@ -1228,5 +1767,60 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
     });
+
+    it("cannot send wrapped tbtc (recipient does not match address format)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination chain and require EVM-style recipients.
+      const recipientChain = 2;
+      const formatIx = await wormholeGateway.updateAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          chain: recipientChain,
+          addressFormat: {
+            length: 20,
+            padding: { left: {} },
+          },
+        }
+      );
+      await expectIxSuccess([formatIx], [authority]);
+
+      // This recipient has no zero padding.
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "InvalidRecipientPadding");
+    });
+
+    after("remove address format", async () => {
+      const removeIx = await wormholeGateway.removeAddressFormatIx(
+        {
+          authority: authority.publicKey,
+        },
+        2
+      );
+      await expectIxSuccess([removeIx], [authority]);
+    });
   });
 });
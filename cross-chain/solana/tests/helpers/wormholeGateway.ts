@@ -49,6 +49,15 @@ export function getGatewayInfoPDA(targetChain: number): PublicKey {
   )[0];
 }
 
+export function getAddressFormatInfoPDA(targetChain: number): PublicKey {
+  const encodedChain = Buffer.alloc(2);
+  encodedChain.writeUInt16LE(targetChain);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("address-format"), encodedChain],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
 export function getWrappedTbtcTokenPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("wrapped-token")],
@@ -101,6 +110,12 @@ export async function getGatewayInfo(chain: number) {
   return program.account.gatewayInfo.fetch(gatewayInfo);
 }
 
+export async function getAddressFormatInfo(chain: number) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  const addressFormatInfo = getAddressFormatInfoPDA(chain);
+  return program.account.addressFormatInfo.fetch(addressFormatInfo);
+}
+
 export async function checkGateway(chain: number, expectedAddress: number[]) {
   const gatewayInfoState = await getGatewayInfo(chain);
   expect(gatewayInfoState.address).to.eql(expectedAddress);
@@ -241,6 +256,106 @@ export async function updateGatewayAddress(
     .instruction();
 }
 
+type UpdateAddressFormatContext = {
+  custodian?: PublicKey;
+  addressFormatInfo?: PublicKey;
+  authority: PublicKey;
+};
+
+type AddressFormat = {
+  length: number;
+  padding: { left: {} } | { right: {} };
+};
+
+type UpdateAddressFormatArgs = {
+  chain: number;
+  addressFormat: AddressFormat;
+};
+
+export async function updateAddressFormatIx(
+  accounts: UpdateAddressFormatContext,
+  args: UpdateAddressFormatArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, addressFormatInfo, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (addressFormatInfo === undefined) {
+    addressFormatInfo = getAddressFormatInfoPDA(args.chain);
+  }
+
+  return program.methods
+    .updateAddressFormat(args)
+    .accounts({
+      custodian,
+      addressFormatInfo,
+      authority,
+    })
+    .instruction();
+}
+
+type RemoveAddressFormatContext = {
+  custodian?: PublicKey;
+  addressFormatInfo?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function removeAddressFormatIx(
+  accounts: RemoveAddressFormatContext,
+  chain: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, addressFormatInfo, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (addressFormatInfo === undefined) {
+    addressFormatInfo = getAddressFormatInfoPDA(chain);
+  }
+
+  return program.methods
+    .removeAddressFormat(chain)
+    .accounts({
+      custodian,
+      addressFormatInfo,
+      authority,
+    })
+    .instruction();
+}
+
+type ValidateRecipientContext = {
+  addressFormatInfo?: PublicKey;
+};
+
+type ValidateRecipientArgs = {
+  chain: number;
+  recipient: number[];
+};
+
+export async function validateRecipientIx(
+  accounts: ValidateRecipientContext,
+  args: ValidateRecipientArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { addressFormatInfo } = accounts;
+
+  if (addressFormatInfo === undefined) {
+    addressFormatInfo = getAddressFormatInfoPDA(args.chain);
+  }
+
+  return program.methods
+    .validateRecipient(args)
+    .accounts({
+      addressFormatInfo,
+    })
+    .instruction();
+}
+
 type DepositWormholeTbtcContext = {
   custodian?: PublicKey;
   wrappedTbtcToken?: PublicKey;
@@ -492,6 +607,7 @@ export async function receiveTbtcIx(
 type SendTbtcGatewayContext = {
   custodian?: PublicKey;
   gatewayInfo?: PublicKey;
+  addressFormatInfo?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
@@ -527,6 +643,7 @@ export async function sendTbtcGatewayIx(
   let {
     custodian,
     gatewayInfo,
+    addressFormatInfo,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
@@ -555,6 +672,10 @@ export async function sendTbtcGatewayIx(
     gatewayInfo = getGatewayInfoPDA(args.recipientChain);
   }
 
+  if (addressFormatInfo === undefined) {
+    addressFormatInfo = getAddressFormatInfoPDA(args.recipientChain);
+  }
+
   if (wrappedTbtcToken === undefined) {
     wrappedTbtcToken = getWrappedTbtcTokenPDA();
   }
@@ -634,6 +755,7 @@ export async function sendTbtcGatewayIx(
     .accounts({
       custodian,
       gatewayInfo,
+      addressFormatInfo,
       wrappedTbtcToken,
       wrappedTbtcMint,
       tbtcMint,
@@ -658,6 +780,7 @@ export async function sendTbtcGatewayIx(
 
 type SendTbtcWrappedContext = {
   custodian?: PublicKey;
+  addressFormatInfo?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
@@ -692,6 +815,7 @@ export async function sendTbtcWrappedIx(
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
+    addressFormatInfo,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
@@ -715,6 +839,10 @@ export async function sendTbtcWrappedIx(
     custodian = getCustodianPDA();
   }
 
+  if (addressFormatInfo === undefined) {
+    addressFormatInfo = getAddressFormatInfoPDA(args.recipientChain);
+  }
+
   if (wrappedTbtcToken === undefined) {
     wrappedTbtcToken = getWrappedTbtcTokenPDA();
   }
@@ -787,6 +915,7 @@ export async function sendTbtcWrappedIx(
     .sendTbtcWrapped(args)
     .accounts({
       custodian,
+      addressFormatInfo,
       wrappedTbtcToken,
       wrappedTbtcMint,
       tbtcMint,